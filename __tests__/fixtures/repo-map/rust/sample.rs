@@ -1,9 +1,67 @@
 use std::collections::{HashMap, HashSet};
 use std::fmt;
+use std::hash::{BuildHasherDefault, Hasher};
+
+pub type FastHashMap<K, V, S = BuildHasherDefault<FnvHasher>> = HashMap<K, V, S>;
+pub type FastHashSet<V, S = BuildHasherDefault<FnvHasher>> = HashSet<V, S>;
+
+pub struct FnvHasher(u64);
+
+impl Default for FnvHasher {
+    fn default() -> Self {
+        FnvHasher(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for FnvHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        let mut hash = self.0;
+        for byte in bytes {
+            hash ^= u64::from(*byte);
+            hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+        self.0 = hash;
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
 
 pub struct PublicStruct {
     value: i32,
 }
+
+impl PublicStruct {
+    pub fn with(value: i32) -> Self {
+        PublicStruct { value }
+    }
+
+    pub fn value(&self) -> i32 {
+        self.value
+    }
+
+    pub fn builder() -> PublicStructBuilder {
+        PublicStructBuilder::default()
+    }
+}
+
+#[derive(Default)]
+pub struct PublicStructBuilder {
+    value: i32,
+}
+
+impl PublicStructBuilder {
+    pub fn value(mut self, value: i32) -> Self {
+        self.value = value;
+        self
+    }
+
+    pub fn build(self) -> PublicStruct {
+        PublicStruct { value: self.value }
+    }
+}
+
 struct PrivateStruct {
     value: i32,
 }
@@ -12,6 +70,39 @@ pub enum PublicEnum {
     One,
     Two,
 }
+
+impl fmt::Display for PublicEnum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let token = match self {
+            PublicEnum::One => "one",
+            PublicEnum::Two => "two",
+        };
+        f.write_str(token)
+    }
+}
+
+impl std::str::FromStr for PublicEnum {
+    type Err = ParsePublicEnumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "one" => Ok(PublicEnum::One),
+            "two" => Ok(PublicEnum::Two),
+            _ => Err(ParsePublicEnumError(s.to_string())),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ParsePublicEnumError(String);
+
+impl fmt::Display for ParsePublicEnumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid PublicEnum token: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParsePublicEnumError {}
 pub trait PublicTrait {
     fn run(&self);
 }
@@ -25,3 +116,35 @@ pub fn public_fn() -> i32 {
 fn private_fn() -> i32 {
     2
 }
+
+/// Returns the index of the end of the first window of `n` consecutive,
+/// pairwise-distinct elements in `items`, or `None` if no such window exists.
+pub fn first_distinct_run<T: std::hash::Hash + Eq>(items: &[T], n: usize) -> Option<usize> {
+    if n == 0 || n > items.len() {
+        return None;
+    }
+
+    let mut counts: HashMap<&T, usize> = HashMap::new();
+    let mut left = 0;
+
+    for right in 0..items.len() {
+        let item = &items[right];
+        *counts.entry(item).or_insert(0) += 1;
+
+        while *counts.get(item).unwrap() > 1 || right - left + 1 > n {
+            let leaving = &items[left];
+            let count = counts.get_mut(leaving).unwrap();
+            *count -= 1;
+            if *count == 0 {
+                counts.remove(leaving);
+            }
+            left += 1;
+        }
+
+        if right - left + 1 == n {
+            return Some(right);
+        }
+    }
+
+    None
+}